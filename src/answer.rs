@@ -0,0 +1,13 @@
+/// Typed result produced by a built-in input-prompt menu item
+/// (see [`ActionBuilder::input_int`], [`ActionBuilder::confirm`], etc).
+///
+/// [`ActionBuilder::input_int`]: struct.ActionBuilder.html#method.input_int
+/// [`ActionBuilder::confirm`]: struct.ActionBuilder.html#method.confirm
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    String(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Choice { index: usize, name: String },
+}
@@ -0,0 +1,58 @@
+use std::fmt;
+use std::io;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::Utf8Error;
+
+/// Error for input-prompt menu items whose input can't be read or parsed.
+///
+/// The built-in `input_*` actions convert parse failures into this type only
+/// to print them as an inline message before re-prompting; they still return
+/// `io::Result`, so a failed read comes back as a plain `io::Error`.
+/// `Io` and `Utf8` are part of the type's contract for custom prompt actions
+/// built with `?`/`.into()` on top of raw reads; none of the built-in
+/// `input_*`/`confirm` actions construct them, and the only variants they
+/// construct are `ParseInt` and `ParseFloat`.
+#[derive(Debug)]
+pub enum TermSelectError {
+    Io(io::Error),
+    ParseInt(ParseIntError),
+    ParseFloat(ParseFloatError),
+    Utf8(Utf8Error),
+}
+
+impl fmt::Display for TermSelectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TermSelectError::Io(e) => write!(f, "{}", e),
+            TermSelectError::ParseInt(e) => write!(f, "{}", e),
+            TermSelectError::ParseFloat(e) => write!(f, "{}", e),
+            TermSelectError::Utf8(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for TermSelectError {}
+
+impl From<io::Error> for TermSelectError {
+    fn from(e: io::Error) -> Self {
+        TermSelectError::Io(e)
+    }
+}
+
+impl From<ParseIntError> for TermSelectError {
+    fn from(e: ParseIntError) -> Self {
+        TermSelectError::ParseInt(e)
+    }
+}
+
+impl From<ParseFloatError> for TermSelectError {
+    fn from(e: ParseFloatError) -> Self {
+        TermSelectError::ParseFloat(e)
+    }
+}
+
+impl From<Utf8Error> for TermSelectError {
+    fn from(e: Utf8Error) -> Self {
+        TermSelectError::Utf8(e)
+    }
+}
@@ -54,9 +54,13 @@
 //! ```
 
 mod selector;
-use selector::FuncBox;
+mod answer;
+mod error;
+use selector::{FuncBox, MultiFuncBox};
 
-pub use crate::selector::{Color, Term, Selector, SelectAction};
+pub use crate::selector::{Color, Key, Term, Selector, SelectAction, Row, Cell};
+pub use crate::answer::Answer;
+pub use crate::error::TermSelectError;
 
 /// Builder for the sub menu items action. This closure is passed a
 /// 'Term' and the previous menu items result from the action
@@ -65,14 +69,16 @@ pub struct SubActionBuilder<'a, T> {
     sub: Option<Selector<'a, T>>,
     name: &'a str,
     func: Option<FuncBox<'a, T>>,
+    hotkey: Option<char>,
+    hint: Option<&'a str>,
     prev_builder: &'a mut SubBuilder<'a, T>,
 }
 impl<'a, T: Clone + 'static> SubActionBuilder<'a, T> {
 
-    /// The Option<T> is passed in via the [`AppBuilder::display`] and 
+    /// The Option<T> is passed in via the [`AppBuilder::display`] and
     /// when a menu items action returns a Result it is passed
     /// to sub menu actions.
-    pub fn action<F>(&mut self, f: F) -> &mut SubActionBuilder<'a, T> 
+    pub fn action<F>(&mut self, f: F) -> &mut SubActionBuilder<'a, T>
     where
         F: Fn(Term, Option<T>) -> std::io::Result<Option<T>> + 'static,
     {
@@ -80,6 +86,18 @@ impl<'a, T: Clone + 'static> SubActionBuilder<'a, T> {
         self
     }
 
+    /// Assigns a mnemonic key that jumps to and fires this item directly.
+    pub fn hotkey(&mut self, key: char) -> &mut SubActionBuilder<'a, T> {
+        self.hotkey = Some(key);
+        self
+    }
+
+    /// Sets a hint shown under this item while it is highlighted.
+    pub fn hint(&mut self, hint: &'a str) -> &mut SubActionBuilder<'a, T> {
+        self.hint = Some(hint);
+        self
+    }
+
     /// Sub menu for your sub menu anyone!
     pub fn sub_menu(&'a mut self) -> SubBuilder<'a, T> {
         SubBuilder::new(self.prev_builder.action)
@@ -88,8 +106,14 @@ impl<'a, T: Clone + 'static> SubActionBuilder<'a, T> {
     /// Adds the sub menu to the `Selector`.
     pub fn push_sub_menu(&'a mut self) -> &'a mut ActionBuilder<'a, T> {
         assert!(self.func.is_some());
-        let sel_action = SelectAction::new(self.name, self.func.take().unwrap(), None);
-        
+        let sel_action = SelectAction::new(self.name, self.func.take().unwrap(), None)
+            .with_hotkey(self.hotkey.take())
+            .with_hint(self.hint.take());
+
+        // Keep what `SubBuilder::label` already put in this sub menu.
+        if let Some(existing) = self.prev_builder.action.sub.take() {
+            self.sub = Some(existing);
+        }
         if let Some(add_to_sub) = &mut self.sub {
             add_to_sub.item_handles.push(sel_action);
             add_to_sub.items.push(self.name);
@@ -120,7 +144,7 @@ impl<'s, T: Clone + 'static> SubBuilder<'s, T> {
         // we need one or the other in order to show selected menu item
         assert!(self.sel_char.is_some() || self.color.is_some());
         let menu = Selector::default();
-        SubActionBuilder { sub: Some(menu), name, func: None, prev_builder: self }
+        SubActionBuilder { sub: Some(menu), name, func: None, hotkey: None, hint: None, prev_builder: self }
     }
     /// Sets the sub menu's highlight color.
     pub fn select_color(&mut self, color: Color) -> &mut SubBuilder<'s, T> {
@@ -133,10 +157,19 @@ impl<'s, T: Clone + 'static> SubBuilder<'s, T> {
         self
     }
 
-    /// Marker to separate sub menu items visually. 
+    /// Marker to separate sub menu items visually.
     pub fn new_sub_menu_item(&mut self) -> &mut SubBuilder<'s, T> {
         self
     }
+
+    /// Adds a non-selectable label, such as a title or separator, that the
+    /// cursor skips over.
+    pub fn label(&mut self, name: &'s str) -> &mut SubBuilder<'s, T> {
+        let sub = self.action.sub.get_or_insert_with(Selector::default);
+        sub.item_handles.push(SelectAction::new_label(name));
+        sub.items.push(name);
+        self
+    }
 }
 
 /// Builder for the items action. This closure is passed a
@@ -146,18 +179,22 @@ pub struct ActionBuilder<'a, T> {
     sub: Option<Selector<'a, T>>,
     name: &'a str,
     func: Option<FuncBox<'a, T>>,
+    multi_func: Option<MultiFuncBox<T>>,
+    hotkey: Option<char>,
+    hint: Option<&'a str>,
+    row: Option<Row>,
     app: &'a mut AppBuilder<'a, T>
 }
 impl<'a, T: Clone + 'static> ActionBuilder<'a, T> {
 
     fn new(name: &'a str, app: &'a mut AppBuilder<'a, T>) -> ActionBuilder<'a, T> {
-        ActionBuilder { sub: None, name, func: None, app }
+        ActionBuilder { sub: None, name, func: None, multi_func: None, hotkey: None, hint: None, row: None, app }
     }
 
-    /// The Option<T> is passed in via the AppBuilder::display() and 
+    /// The Option<T> is passed in via the AppBuilder::display() and
     /// when a menu item's action returns a Result it is always passed
     /// to that menu items submenu actions, if there is one.
-    pub fn action<F>(&mut self, f: F) -> &mut ActionBuilder<'a, T> 
+    pub fn action<F>(&mut self, f: F) -> &mut ActionBuilder<'a, T>
     where
         F: Fn(Term, Option<T>) -> std::io::Result<Option<T>> + 'static,
     {
@@ -165,6 +202,37 @@ impl<'a, T: Clone + 'static> ActionBuilder<'a, T> {
         self
     }
 
+    /// Runs instead of [`action`] when the menu is in multi-select mode and
+    /// at least one item is toggled on; receives the toggled item names.
+    ///
+    /// [`action`]: struct.ActionBuilder.html#method.action
+    pub fn multi_action<F>(&mut self, f: F) -> &mut ActionBuilder<'a, T>
+    where
+        F: Fn(Term, Vec<String>) -> std::io::Result<Option<T>> + 'static,
+    {
+        self.multi_func = Some(Box::new(f));
+        self
+    }
+
+    /// Assigns a mnemonic key that jumps to and fires this item directly.
+    pub fn hotkey(&mut self, key: char) -> &mut ActionBuilder<'a, T> {
+        self.hotkey = Some(key);
+        self
+    }
+
+    /// Sets a hint shown under this item while it is highlighted.
+    pub fn hint(&mut self, hint: &'a str) -> &mut ActionBuilder<'a, T> {
+        self.hint = Some(hint);
+        self
+    }
+
+    /// Lays the item out as aligned columns (e.g. name + kind + shortcut)
+    /// instead of a single string.
+    pub fn row(&mut self, cells: Vec<&'a str>) -> &mut ActionBuilder<'a, T> {
+        self.row = Some(Row::new(cells));
+        self
+    }
+
     /// Adds a sub_menu to the current menu item
     pub fn sub_menu(&'a mut self) -> SubBuilder<'a, T> {
         SubBuilder::new(self)
@@ -173,22 +241,124 @@ impl<'a, T: Clone + 'static> ActionBuilder<'a, T> {
     /// Adds the sub_menu to the current menu item.
     pub fn push_menu_item(&'a mut self) -> &'a mut AppBuilder<'a, T> {
         assert!(self.func.is_some());
-        let sel_action = SelectAction::new(self.name, self.func.take().unwrap(), self.sub.take());
+        let sel_action = SelectAction::new(self.name, self.func.take().unwrap(), self.sub.take())
+            .with_multi_func(self.multi_func.take())
+            .with_hotkey(self.hotkey.take())
+            .with_hint(self.hint.take())
+            .with_row(self.row.take());
         self.app.menu.item_handles.push(sel_action);
         self.app.menu.items.push(self.name);
         self.app
     }
 }
 
+impl<'a> ActionBuilder<'a, Answer> {
+    /// Registers a built-in action that prompts for a line of text and
+    /// yields it as `Answer::String`.
+    pub fn input_text(&mut self, prompt: &'a str) -> &mut ActionBuilder<'a, Answer> {
+        let prompt = prompt.to_string();
+        self.action(move |t: Term, _res: Option<Answer>| -> std::io::Result<Option<Answer>> {
+            t.write_str(&prompt)?;
+            let line = t.read_line()?;
+            Ok(Some(Answer::String(line)))
+        })
+    }
+
+    /// Registers a built-in action that prompts for an integer, re-prompting
+    /// with an inline error message until the input parses, and yields the
+    /// result as `Answer::Int`.
+    pub fn input_int(&mut self, prompt: &'a str) -> &mut ActionBuilder<'a, Answer> {
+        let prompt = prompt.to_string();
+        self.action(move |t: Term, _res: Option<Answer>| -> std::io::Result<Option<Answer>> {
+            loop {
+                t.write_str(&prompt)?;
+                let line = t.read_line()?;
+                match line.trim().parse::<i64>() {
+                    Ok(n) => return Ok(Some(Answer::Int(n))),
+                    Err(e) => {
+                        let err: TermSelectError = e.into();
+                        t.write_line(&format!("invalid number: {}", err))?;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Registers a built-in action that prompts for a float, re-prompting
+    /// with an inline error message until the input parses, and yields the
+    /// result as `Answer::Float`.
+    pub fn input_float(&mut self, prompt: &'a str) -> &mut ActionBuilder<'a, Answer> {
+        let prompt = prompt.to_string();
+        self.action(move |t: Term, _res: Option<Answer>| -> std::io::Result<Option<Answer>> {
+            loop {
+                t.write_str(&prompt)?;
+                let line = t.read_line()?;
+                match line.trim().parse::<f64>() {
+                    Ok(n) => return Ok(Some(Answer::Float(n))),
+                    Err(e) => {
+                        let err: TermSelectError = e.into();
+                        t.write_line(&format!("invalid number: {}", err))?;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Registers a built-in action that prompts for `y`/`n` and yields the
+    /// result as `Answer::Bool`.
+    pub fn confirm(&mut self, prompt: &'a str) -> &mut ActionBuilder<'a, Answer> {
+        let prompt = prompt.to_string();
+        self.action(move |t: Term, _res: Option<Answer>| -> std::io::Result<Option<Answer>> {
+            loop {
+                t.write_str(&format!("{} (y/n) ", prompt))?;
+                match t.read_key()? {
+                    Key::Char('y') | Key::Char('Y') => return Ok(Some(Answer::Bool(true))),
+                    Key::Char('n') | Key::Char('N') => return Ok(Some(Answer::Bool(false))),
+                    _ => t.write_line("please answer y or n")?,
+                }
+            }
+        })
+    }
+
+    /// Registers a built-in action that lists `choices`, prompts for a
+    /// 1-based pick, re-prompting until the input is in range, and yields
+    /// the result as `Answer::Choice`.
+    pub fn input_choice(&mut self, prompt: &'a str, choices: Vec<&'a str>) -> &mut ActionBuilder<'a, Answer> {
+        let prompt = prompt.to_string();
+        let choices: Vec<String> = choices.into_iter().map(String::from).collect();
+        self.action(move |t: Term, _res: Option<Answer>| -> std::io::Result<Option<Answer>> {
+            loop {
+                t.write_line(&prompt)?;
+                for (i, choice) in choices.iter().enumerate() {
+                    t.write_line(&format!("  {}) {}", i + 1, choice))?;
+                }
+                let line = t.read_line()?;
+                match line.trim().parse::<usize>() {
+                    Ok(n) if n >= 1 && n <= choices.len() => {
+                        let index = n - 1;
+                        return Ok(Some(Answer::Choice { index, name: choices[index].clone() }));
+                    }
+                    Ok(_) => t.write_line("choice out of range")?,
+                    Err(e) => {
+                        let err: TermSelectError = e.into();
+                        t.write_line(&format!("invalid choice: {}", err))?;
+                    }
+                }
+            }
+        })
+    }
+}
+
 /// Builds a selectable menu.
 pub struct AppBuilder<'s, T> {
     menu: Selector<'s, T>,
     color: Option<Color>,
     sel_char: Option<&'s str>,
+    multi_select: bool,
 }
 impl<'s, T> Default for AppBuilder<'s, T> {
     fn default() -> Self {
-        Self { menu: Selector::default(), color: None, sel_char: None }
+        Self { menu: Selector::default(), color: None, sel_char: None, multi_select: false }
     }
 }
 impl<'s, T: Clone + 'static> AppBuilder<'s, T> {
@@ -196,7 +366,7 @@ impl<'s, T: Clone + 'static> AppBuilder<'s, T> {
     pub fn new() -> Self {
         Self::default()
     }
-    /// Sets the title of the menu item. Returns `ActionBuilder` 
+    /// Sets the title of the menu item. Returns `ActionBuilder`
     /// to build action closure.
     pub fn item_name(&'s mut self, name: &'s str) -> ActionBuilder<'s, T> {
         ActionBuilder::new(name, self)
@@ -212,16 +382,35 @@ impl<'s, T: Clone + 'static> AppBuilder<'s, T> {
         self.sel_char = Some(select_char);
         self
     }
-    /// Marker to separate menu items visually. 
+    /// Marker to separate menu items visually.
     pub fn new_menu_item(&mut self) -> &mut AppBuilder<'s, T> {
         self
     }
+    /// Adds a non-selectable label, such as a title or separator, that the
+    /// cursor skips over.
+    pub fn label(&mut self, name: &'s str) -> &mut AppBuilder<'s, T> {
+        self.menu.item_handles.push(SelectAction::new_label(name));
+        self.menu.items.push(name);
+        self
+    }
+    /// Turns the menu into a checkbox-style multi-select: `Space` toggles
+    /// the highlighted item on/off, and `Enter` passes the names of every
+    /// toggled item to the highlighted item's [`ActionBuilder::multi_action`].
+    /// With nothing toggled, or no `multi_action` set, `Enter` runs the plain
+    /// action as usual. Sub menus are not put into multi-select mode.
+    ///
+    /// [`ActionBuilder::multi_action`]: struct.ActionBuilder.html#method.multi_action
+    pub fn multi_select(&mut self) -> &mut AppBuilder<'s, T> {
+        self.multi_select = true;
+        self
+    }
     /// Starts the display loop, this needs to be the last called.
     pub fn display(&mut self, term: &Term, res: Option<T>) -> Result<(), std::io::Error> {
         // we need one or the other in order to show selected menu item
         assert!(self.sel_char.is_some() || self.color.is_some());
         self.menu.sel_char = self.sel_char;
         self.menu.sel_color = self.color;
+        self.menu.multi_select = self.multi_select;
         self.menu.display_loop(term, res)
     }
 }
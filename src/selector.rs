@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::io;
 
@@ -5,11 +6,48 @@ use colored::Colorize;
 pub use console::{Color, Key, Term};
 
 pub type FuncBox<'s, T> = Box<dyn Fn(Term, Option<T>) -> io::Result<Option<T>> + 'static>;
+/// Runs when the item is fired (`Enter` or its quick-select key) in a
+/// multi-select menu with at least one item toggled on, receiving the names
+/// of every toggled item instead of the previous result.
+pub type MultiFuncBox<T> = Box<dyn Fn(Term, Vec<String>) -> io::Result<Option<T>> + 'static>;
+
+/// A single column of a [`Row`].
+#[derive(Debug, Clone)]
+pub struct Cell(pub String);
+
+impl Cell {
+    pub fn new<S: Into<String>>(s: S) -> Self {
+        Cell(s.into())
+    }
+
+    fn width(&self) -> usize {
+        self.0.chars().count()
+    }
+}
+
+/// A set of aligned columns rendered in place of a plain menu item string.
+#[derive(Debug, Clone, Default)]
+pub struct Row(pub Vec<Cell>);
+
+impl Row {
+    pub fn new(cells: Vec<&str>) -> Self {
+        Row(cells.into_iter().map(Cell::new).collect())
+    }
+}
 
 pub struct SelectAction<'s, T> {
     pub(crate) item: &'s str,
     pub(crate) sub_menu: Option<Selector<'s, T>>,
     func: FuncBox<'s, T>,
+    pub(crate) multi_func: Option<MultiFuncBox<T>>,
+    /// Labels carry no action and are skipped over by the cursor.
+    pub(crate) is_label: bool,
+    /// Mnemonic key that jumps to and fires this item directly.
+    pub(crate) hotkey: Option<char>,
+    /// Short description shown under this item while it is highlighted.
+    pub(crate) hint: Option<&'s str>,
+    /// Multi-column layout rendered in place of `item` when set.
+    pub(crate) row: Option<Row>,
 }
 
 impl<'s, T> SelectAction<'s, T>
@@ -21,7 +59,47 @@ where
         func: FuncBox<'s, T>,
         sub_menu: Option<Selector<'s, T>>
     ) -> Self {
-        SelectAction { item, sub_menu, func, }
+        SelectAction { item, sub_menu, func, multi_func: None, is_label: false, hotkey: None, hint: None, row: None, }
+    }
+
+    /// A non-selectable item such as a title or separator. The cursor skips
+    /// over it and it is never highlighted.
+    pub fn new_label(item: &'s str) -> Self {
+        SelectAction {
+            item,
+            sub_menu: None,
+            func: Box::new(|_t: Term, res: Option<T>| Ok(res)),
+            multi_func: None,
+            is_label: true,
+            hotkey: None,
+            hint: None,
+            row: None,
+        }
+    }
+
+    /// Attaches the multi-select action that runs with the set of chosen
+    /// item names instead of the usual `Option<T>` result.
+    pub(crate) fn with_multi_func(mut self, multi_func: Option<MultiFuncBox<T>>) -> Self {
+        self.multi_func = multi_func;
+        self
+    }
+
+    /// Attaches the mnemonic key that jumps to and fires this item directly.
+    pub(crate) fn with_hotkey(mut self, hotkey: Option<char>) -> Self {
+        self.hotkey = hotkey;
+        self
+    }
+
+    /// Attaches the hint text shown under this item while it is highlighted.
+    pub(crate) fn with_hint(mut self, hint: Option<&'s str>) -> Self {
+        self.hint = hint;
+        self
+    }
+
+    /// Attaches the multi-column row rendered in place of the plain name.
+    pub(crate) fn with_row(mut self, row: Option<Row>) -> Self {
+        self.row = row;
+        self
     }
 }
 
@@ -33,6 +111,29 @@ pub enum Highlighter<'s> {
     Character(&'s str),
 }
 
+/// One level of the explicit navigation stack [`Selector::display_loop`]
+/// walks instead of recursing into sub menus. Keeps each level's cursor
+/// position, toggled set, and the `Option<T>` it was entered with so that
+/// popping back via `ArrowLeft` restores exactly where the user left off.
+struct NavFrame<'a, 'c, T> {
+    selector: &'a Selector<'c, T>,
+    label: &'c str,
+    index: usize,
+    selected: HashSet<usize>,
+    result: Option<T>,
+}
+
+impl<'a, 'c, T: Clone> NavFrame<'a, 'c, T> {
+    fn new(selector: &'a Selector<'c, T>, label: &'c str, result: Option<T>) -> Self {
+        let index = if selector.item_handles.first().is_some_and(|h| h.is_label) {
+            selector.skip_labels(0, 1)
+        } else {
+            0
+        };
+        NavFrame { selector, label, index, selected: HashSet::new(), result }
+    }
+}
+
 /// Selector for building arrow-able cli programmes.
 ///
 /// Selector consists of a Vec of (str, Fn) the str is the name of the selectable item followed by
@@ -43,6 +144,7 @@ pub struct Selector<'c, T> {
     pub(crate) items: Vec<&'c str>,
     pub(crate) sel_color: Option<Color>,
     pub(crate) sel_char: Option<&'c str>,
+    pub(crate) multi_select: bool,
 }
 
 impl<'c, T> Default for Selector<'c, T> {
@@ -52,6 +154,7 @@ impl<'c, T> Default for Selector<'c, T> {
             items: vec![],
             sel_color: None,
             sel_char: None,
+            multi_select: false,
         }
     }
 }
@@ -108,6 +211,7 @@ where
             items: i,
             sel_color: color,
             sel_char: s_char,
+            multi_select: false,
         }
     }
     fn build_selected_str(&self, s: &str) -> String {
@@ -157,56 +261,219 @@ where
         }
     }
 
-    /// Drives the display of menus and selection.
+    /// Returns `true` if every item in the menu is a non-selectable label.
+    fn all_labels(&self) -> bool {
+        self.item_handles.iter().all(|h| h.is_label)
+    }
+
+    /// Walks the cursor forward (or backward, with `step == -1`) from
+    /// `index`, wrapping around, until it lands on a selectable item.
+    fn skip_labels(&self, mut index: usize, step: isize) -> usize {
+        if self.all_labels() {
+            return index;
+        }
+        loop {
+            index = if step < 0 {
+                if index == 0 { self.items.len() - 1 } else { index - 1 }
+            } else {
+                if index < self.items.len() - 1 { index + 1 } else { 0 }
+            };
+            if !self.item_handles[index].is_label {
+                return index;
+            }
+        }
+    }
+
+    /// Looks up the item whose hotkey matches `c`, falling back to the
+    /// 1-based numeric shortcut (`'1'..='9'`) when no item explicitly
+    /// claims `c` as its hotkey.
+    fn find_quick_select(&self, c: char) -> Option<usize> {
+        if let Some(pos) = self.item_handles.iter().position(|h| h.hotkey == Some(c)) {
+            return Some(pos);
+        }
+
+        let digit = c.to_digit(10)? as usize;
+        if digit == 0 {
+            return None;
+        }
+        let index = digit - 1;
+        if index < self.item_handles.len() && !self.item_handles[index].is_label {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// Runs the action (or multi-select action) for the item at `index`,
+    /// returning the result passed on to its sub menu (if any).
+    fn fire(
+        &self,
+        term: &Term,
+        index: usize,
+        result: &Option<T>,
+        selected: &HashSet<usize>,
+    ) -> Result<Option<T>, io::Error> {
+        let handle = &self.item_handles[index];
+
+        if self.multi_select && !selected.is_empty() {
+            if let Some(multi_func) = &handle.multi_func {
+                let names: Vec<String> = selected.iter().map(|&i| self.items[i].to_string()).collect();
+                return (*multi_func)(term.clone(), names);
+            }
+        }
+
+        // calls the function provided for the selection
+        (*handle.func)(term.clone(), result.clone())
+    }
+
+    /// Drives the display of menus and selection, pushing a [`NavFrame`]
+    /// onto an explicit navigation stack each time a sub menu is entered so
+    /// `ArrowLeft` can reliably pop back one level instead of quitting.
     pub fn display_loop(&self, term: &Term, result: Option<T>) -> Result<(), io::Error> {
-        let mut index = 0;
+        let mut stack: Vec<NavFrame<'_, 'c, T>> = vec![NavFrame::new(self, "Main", result)];
+
         loop {
+            let (selector, index, selected, result) = {
+                let frame = stack.last().unwrap();
+                (frame.selector, frame.index, frame.selected.clone(), frame.result.clone())
+            };
+
+            let explicit_hotkeys: HashSet<char> =
+                selector.item_handles.iter().filter_map(|h| h.hotkey).collect();
+
+            // Column widths for items using a `Row` instead of a plain name.
+            // Only the highlighted row gets a real selection-char prefix from
+            // `build_selected_str`, so every non-highlighted row is front-padded
+            // by that same width below to keep columns aligned.
+            let sel_prefix_width = selector.sel_char.map_or(0, |c| c.chars().count() + 1);
+            let num_cols = selector
+                .item_handles
+                .iter()
+                .filter_map(|h| h.row.as_ref())
+                .map(|row| row.0.len())
+                .max()
+                .unwrap_or(0);
+            let col_widths: Vec<usize> = (0..num_cols)
+                .map(|col| {
+                    selector
+                        .item_handles
+                        .iter()
+                        .filter_map(|h| h.row.as_ref())
+                        .filter_map(|row| row.0.get(col))
+                        .map(|cell| cell.width())
+                        .max()
+                        .unwrap_or(0)
+                })
+                .collect();
+            // The "(n) "/"(c) " mnemonic prefix is only 4 chars wide, but is
+            // absent on any item whose digit is claimed by another item's
+            // explicit hotkey or that runs out of digits past the 9th item.
+            // Reserve the slot on every row so those items don't shift left.
+            let mnemonic_slot_width = if num_cols > 0 { 4 } else { 0 };
+
             // TODO until term.hide_cursor() works
             let esc = "\u{001B}";
             term.write_str(&format!("{}[?25l", esc))?;
             // term.hide_cursor()?;
             term.clear_screen()?;
-            for (i, line) in self.iter().enumerate() {
-                if i == index {
+            for (i, line) in selector.iter().enumerate() {
+                let handle = &selector.item_handles[i];
+
+                let row_text = handle.row.as_ref().map(|row| {
+                    row.0
+                        .iter()
+                        .enumerate()
+                        .map(|(ci, cell)| format!("{:width$}", cell.0, width = col_widths[ci]))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                });
+
+                let mnemonic = handle.hotkey.or_else(|| {
+                    if handle.is_label {
+                        return None;
+                    }
+                    let digit = std::char::from_digit((i + 1) as u32, 10)?;
+                    if explicit_hotkeys.contains(&digit) { None } else { Some(digit) }
+                });
+
+                let mut line = match (mnemonic, &row_text) {
+                    (Some(c), _) => format!("({}) {}", c, row_text.as_deref().unwrap_or(line)),
+                    (None, Some(row_text)) => {
+                        format!("{:width$}{}", "", row_text, width = mnemonic_slot_width)
+                    }
+                    (None, None) => line.to_string(),
+                };
+                if selector.multi_select && !handle.is_label {
+                    let checkbox = if selected.contains(&i) { "[x] " } else { "[ ] " };
+                    line = format!("{}{}", checkbox, line);
+                }
+
+                if i == index && !handle.is_label {
                     // build color and selected char into string
-                    let color_line = self.build_selected_str(line);
+                    let color_line = selector.build_selected_str(&line);
 
                     term.write_line(&color_line)?;
+                    if let Some(hint) = handle.hint {
+                        term.write_line(&hint.dimmed().to_string())?;
+                    }
+                } else if handle.row.is_some() {
+                    // Non-highlighted rows don't get `build_selected_str`'s real
+                    // prefix, so front-pad by the same width to keep columns
+                    // aligned with the highlighted row.
+                    term.write_line(&format!("{:width$}{}", "", line, width = sel_prefix_width))?;
                 } else {
-                    term.write_line(line)?;
+                    term.write_line(&line)?;
                 }
             }
             term.write_str("\r\nEsc to quit Left arrow to go back one menu.")?;
+            if selector.multi_select {
+                term.write_str(" Space to toggle.")?;
+            }
+            let breadcrumb = stack.iter().map(|f| f.label).collect::<Vec<_>>().join(" > ");
+            term.write_str(&format!("\r\n{}", breadcrumb))?;
 
             match term.read_key()? {
                 Key::ArrowDown => {
-                    if index < self.items.len() - 1 {
-                        index += 1;
-                    } else {
-                        index = 0;
-                    }
+                    let frame = stack.last_mut().unwrap();
+                    frame.index = frame.selector.skip_labels(frame.index, 1);
                 }
                 Key::ArrowUp => {
-                    if index != 0 {
-                        index -= 1;
+                    let frame = stack.last_mut().unwrap();
+                    frame.index = frame.selector.skip_labels(frame.index, -1);
+                }
+                Key::Char(' ') if selector.multi_select => {
+                    let frame = stack.last_mut().unwrap();
+                    if frame.selected.contains(&frame.index) {
+                        frame.selected.remove(&frame.index);
                     } else {
-                        index = self.items.len() - 1;
+                        frame.selected.insert(frame.index);
+                    }
+                }
+                Key::Char(c) => {
+                    if let Some(target) = selector.find_quick_select(c) {
+                        stack.last_mut().unwrap().index = target;
+                        let handle = &selector.item_handles[target];
+                        let res = selector.fire(term, target, &result, &selected)?;
+                        if let Some(sub) = &handle.sub_menu {
+                            stack.push(NavFrame::new(sub, handle.item, res));
+                        }
                     }
                 }
                 Key::Enter => {
-                    let handle = &self.item_handles[index];
-                    // calls the function provided for the selection
-                    let res = (*handle.func)(term.clone(), result.clone())?;
-
-                    if let Some(sub) = &self.item_handles[index].sub_menu {
-                        sub.display_loop(term, res)?;
+                    let handle = &selector.item_handles[index];
+                    let res = selector.fire(term, index, &result, &selected)?;
+                    if let Some(sub) = &handle.sub_menu {
+                        stack.push(NavFrame::new(sub, handle.item, res));
                     }
                 }
                 Key::ArrowLeft => {
-                    // this will allow back button
-                    // how to check if we are at top level
-                    term.clear_screen()?;
-                    return Ok(());
+                    // pop back one level; only quits once the top menu is reached
+                    if stack.len() > 1 {
+                        stack.pop();
+                    } else {
+                        term.clear_screen()?;
+                        return Ok(());
+                    }
                 }
                 Key::Escape => {
                     term.show_cursor()?;